@@ -108,6 +108,8 @@ pub struct AppConfig {
 
     pub enable_cover_image_cache: bool,
 
+    pub enable_bidi: bool,
+
     pub default_device: String,
 
     pub device: DeviceConfig,
@@ -328,6 +330,8 @@ impl Default for AppConfig {
 
             enable_cover_image_cache: true,
 
+            enable_bidi: true,
+
             default_device: "spotify-player".to_string(),
 
             device: DeviceConfig::default(),
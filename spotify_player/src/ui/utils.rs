@@ -2,7 +2,7 @@ use super::{
     config, Block, BorderType, Borders, Frame, List, ListItem, ListState, Rect, Span, Style, Table,
     TableState,
 };
-use unicode_bidi::BidiInfo;
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo};
 
 /// Construct and render a block.
 ///
@@ -120,9 +120,30 @@ pub fn render_table_window(
     frame.render_stateful_widget(widget, rect, state);
 }
 
+/// Check whether a string contains any character that can make it right-to-left.
+/// This is much cheaper than building a `BidiInfo` and is used to skip bidi
+/// processing for the common case of LTR-only text.
+fn may_contain_rtl(s: &str) -> bool {
+    s.chars().any(|c| {
+        matches!(
+            bidi_class(c),
+            BidiClass::R
+                | BidiClass::AL
+                | BidiClass::AN
+                | BidiClass::RLE
+                | BidiClass::RLO
+                | BidiClass::RLI
+        )
+    })
+}
+
 /// Convert a string to a bidirectional string.
 /// Used to handle RTL text properly in the UI.
 pub fn to_bidi_string(s: &str) -> String {
+    if !config::get_config().app_config.enable_bidi || !may_contain_rtl(s) {
+        return s.to_string();
+    }
+
     let bidi_info = BidiInfo::new(s, None);
 
     let bidi_string = if bidi_info.has_rtl() && !bidi_info.paragraphs.is_empty() {